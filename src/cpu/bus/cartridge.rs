@@ -0,0 +1,176 @@
+//! Cartridge/MBC (Memory Bank Controller) abstraction.
+//!
+//! Real Game Boy cartridges are bigger than the 32 KiB that fits directly
+//! into the `0x0000-0x7FFF` ROM window, so they ship a controller chip that
+//! swaps banks in and out as the CPU writes to "registers" that are really
+//! just ROM addresses. [`Cartridge`] hides that behind a couple of
+//! memory-mapped read/write calls so [`super::Bus`] doesn't need to know
+//! which controller a given ROM uses.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// A cartridge's ROM (and optional external RAM), addressable through the
+/// `0x0000-0x7FFF` (ROM) and `0xA000-0xBFFF` (external RAM) windows.
+pub trait Cartridge: Debug {
+    /// Reads a byte mapped into the ROM or external RAM window.
+    fn read(&self, addr: u16) -> u8;
+    /// Writes a byte into the external RAM window, or into the MBC's
+    /// bank-switching registers if `addr` falls in the ROM window.
+    fn write(&mut self, addr: u16, val: u8);
+}
+
+/// A cartridge with no memory bank controller: a single fixed 32 KiB ROM
+/// and no external RAM.
+#[derive(Debug)]
+pub struct NoMbc {
+    rom: Vec<u8>,
+}
+
+impl NoMbc {
+    pub fn new(rom: Vec<u8>) -> Self {
+        Self { rom }
+    }
+}
+
+impl Cartridge for NoMbc {
+    fn read(&self, addr: u16) -> u8 {
+        self.rom.get(addr as usize).copied().unwrap_or(0xFF)
+    }
+
+    fn write(&mut self, _addr: u16, _val: u8) {
+        // No bank-switching registers and no external RAM to write to.
+    }
+}
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+/// MBC1, the most common controller, supporting up to 2 MiB of ROM and
+/// 32 KiB of external RAM via a 5-bit ROM bank register and a 2-bit
+/// secondary register shared between RAM banking and the upper ROM bank
+/// bits.
+#[derive(Debug)]
+pub struct Mbc1 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    ram_enabled: bool,
+    rom_bank: u8,
+    secondary_bank: u8,
+    /// `false` selects the extra ROM banking mode, `true` selects RAM
+    /// banking mode, mirroring the real MBC1 mode register.
+    ram_banking_mode: bool,
+}
+
+impl Mbc1 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> Self {
+        Self {
+            rom,
+            ram: vec![0; ram_size],
+            ram_enabled: false,
+            rom_bank: 1,
+            secondary_bank: 0,
+            ram_banking_mode: false,
+        }
+    }
+
+    fn rom_bank_number(&self) -> usize {
+        // Bank 0 is never actually selectable; the register wraps to 1.
+        let bank = if self.rom_bank == 0 { 1 } else { self.rom_bank };
+        if self.ram_banking_mode {
+            bank as usize
+        } else {
+            (bank as usize) | ((self.secondary_bank as usize) << 5)
+        }
+    }
+
+    fn ram_bank_number(&self) -> usize {
+        if self.ram_banking_mode {
+            self.secondary_bank as usize
+        } else {
+            0
+        }
+    }
+}
+
+impl Cartridge for Mbc1 {
+    fn read(&self, addr: u16) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => self.rom.get(addr as usize).copied().unwrap_or(0xFF),
+            0x4000..=0x7FFF => {
+                let offset = self.rom_bank_number() * ROM_BANK_SIZE + (addr as usize - 0x4000);
+                self.rom.get(offset).copied().unwrap_or(0xFF)
+            }
+            0xA000..=0xBFFF if self.ram_enabled => {
+                let offset = self.ram_bank_number() * RAM_BANK_SIZE + (addr as usize - 0xA000);
+                self.ram.get(offset).copied().unwrap_or(0xFF)
+            }
+            _ => 0xFF,
+        }
+    }
+
+    fn write(&mut self, addr: u16, val: u8) {
+        match addr {
+            0x0000..=0x1FFF => self.ram_enabled = val & 0x0F == 0x0A,
+            0x2000..=0x3FFF => self.rom_bank = val & 0b0001_1111,
+            0x4000..=0x5FFF => self.secondary_bank = val & 0b11,
+            0x6000..=0x7FFF => self.ram_banking_mode = val & 0b1 == 0b1,
+            0xA000..=0xBFFF if self.ram_enabled => {
+                let offset = self.ram_bank_number() * RAM_BANK_SIZE + (addr as usize - 0xA000);
+                if let Some(byte) = self.ram.get_mut(offset) {
+                    *byte = val;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_mbc() {
+        let mut rom = vec![0; 0x8000];
+        rom[0x0100] = 0xAB;
+        let cart = NoMbc::new(rom);
+        assert_eq!(cart.read(0x0100), 0xAB);
+        assert_eq!(cart.read(0x7FFF), 0x00);
+    }
+
+    #[test]
+    fn test_mbc1_bank_switching() {
+        let mut rom = vec![0; ROM_BANK_SIZE * 4];
+        rom[ROM_BANK_SIZE * 2] = 0xCA;
+        rom[ROM_BANK_SIZE * 3 + 1] = 0xFE;
+        let mut cart = Mbc1::new(rom, RAM_BANK_SIZE);
+
+        cart.write(0x2000, 0x02);
+        assert_eq!(cart.read(0x4000), 0xCA);
+
+        cart.write(0x2000, 0x03);
+        assert_eq!(cart.read(0x4001), 0xFE);
+
+        // Bank register 0 wraps around to bank 1.
+        cart.write(0x2000, 0x00);
+        assert_eq!(cart.rom_bank_number(), 1);
+    }
+
+    #[test]
+    fn test_mbc1_ram_enable() {
+        let cart_rom = vec![0; ROM_BANK_SIZE * 2];
+        let mut cart = Mbc1::new(cart_rom, RAM_BANK_SIZE);
+
+        // RAM is disabled by default.
+        cart.write(0xA000, 0x42);
+        assert_eq!(cart.read(0xA000), 0xFF);
+
+        cart.write(0x0000, 0x0A);
+        cart.write(0xA000, 0x42);
+        assert_eq!(cart.read(0xA000), 0x42);
+    }
+}
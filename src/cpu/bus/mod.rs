@@ -0,0 +1,176 @@
+//! The Game Boy's 64 KiB address space, and the [`Bus`] that routes reads
+//! and writes to the region backing a given address, as laid out in the
+//! [Pan Docs memory map](https://gbdev.io/pandocs/Memory_Map.html).
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use cartridge::{Cartridge, NoMbc};
+
+pub mod cartridge;
+
+const VRAM_SIZE: usize = 0x2000;
+const WRAM_SIZE: usize = 0x2000;
+const OAM_SIZE: usize = 0xA0;
+const IO_SIZE: usize = 0x80;
+const HRAM_SIZE: usize = 0x7F;
+
+/// Something that can be read from and written to by address, such as the
+/// [`Bus`] itself or one of its backing regions.
+pub trait Addressable {
+    fn read(&self, addr: u16) -> u8;
+    fn write(&mut self, addr: u16, val: u8);
+}
+
+/// The memory region a given address is routed to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MemoryMap {
+    /// `0x0000-0x3FFF`: fixed bank-zero cartridge ROM.
+    RomBank0,
+    /// `0x4000-0x7FFF`: switchable cartridge ROM bank.
+    RomBankN,
+    /// `0x8000-0x9FFF`: video RAM.
+    Vram,
+    /// `0xA000-0xBFFF`: cartridge (possibly banked) external RAM.
+    ExternalRam,
+    /// `0xC000-0xDFFF` (and its `0xE000-0xFDFF` echo): work RAM.
+    Wram,
+    /// `0xFE00-0xFE9F`: object attribute memory, i.e. sprite data.
+    Oam,
+    /// `0xFEA0-0xFEFF`: unusable, reads as `0xFF` and ignores writes.
+    Unusable,
+    /// `0xFF00-0xFF7F`: I/O registers.
+    Io,
+    /// `0xFF80-0xFFFE`: high RAM.
+    Hram,
+    /// `0xFFFF`: the interrupt enable register.
+    InterruptEnable,
+}
+
+impl MemoryMap {
+    pub fn from_addr(addr: u16) -> Self {
+        match addr {
+            0x0000..=0x3FFF => Self::RomBank0,
+            0x4000..=0x7FFF => Self::RomBankN,
+            0x8000..=0x9FFF => Self::Vram,
+            0xA000..=0xBFFF => Self::ExternalRam,
+            0xC000..=0xFDFF => Self::Wram,
+            0xFE00..=0xFE9F => Self::Oam,
+            0xFEA0..=0xFEFF => Self::Unusable,
+            0xFF00..=0xFF7F => Self::Io,
+            0xFF80..=0xFFFE => Self::Hram,
+            0xFFFF => Self::InterruptEnable,
+        }
+    }
+}
+
+/// The Game Boy's full 64 KiB address space, owning the cartridge and every
+/// memory-mapped region the CPU can see.
+#[derive(Debug)]
+pub struct Bus {
+    cartridge: Box<dyn Cartridge>,
+    vram: [u8; VRAM_SIZE],
+    wram: [u8; WRAM_SIZE],
+    oam: [u8; OAM_SIZE],
+    io: [u8; IO_SIZE],
+    hram: [u8; HRAM_SIZE],
+    interrupt_enable: u8,
+}
+
+impl Bus {
+    pub fn new(cartridge: Box<dyn Cartridge>) -> Self {
+        Self {
+            cartridge,
+            vram: [0; VRAM_SIZE],
+            wram: [0; WRAM_SIZE],
+            oam: [0; OAM_SIZE],
+            io: [0; IO_SIZE],
+            hram: [0; HRAM_SIZE],
+            interrupt_enable: 0,
+        }
+    }
+
+    /// Builds a [`Bus`] around a ROM with no memory bank controller, e.g.
+    /// for 32 KiB homebrew test ROMs.
+    pub fn with_rom(rom: Vec<u8>) -> Self {
+        Self::new(Box::new(NoMbc::new(rom)))
+    }
+
+    pub fn interrupt_enable(&self) -> u8 {
+        self.interrupt_enable
+    }
+
+    pub fn set_interrupt_enable(&mut self, val: u8) {
+        self.interrupt_enable = val;
+    }
+}
+
+impl Addressable for Bus {
+    fn read(&self, addr: u16) -> u8 {
+        match MemoryMap::from_addr(addr) {
+            MemoryMap::RomBank0 | MemoryMap::RomBankN | MemoryMap::ExternalRam => {
+                self.cartridge.read(addr)
+            }
+            MemoryMap::Vram => self.vram[addr as usize - 0x8000],
+            MemoryMap::Wram => self.wram[(addr as usize - 0xC000) % WRAM_SIZE],
+            MemoryMap::Oam => self.oam[addr as usize - 0xFE00],
+            MemoryMap::Unusable => 0xFF,
+            MemoryMap::Io => self.io[addr as usize - 0xFF00],
+            MemoryMap::Hram => self.hram[addr as usize - 0xFF80],
+            MemoryMap::InterruptEnable => self.interrupt_enable,
+        }
+    }
+
+    fn write(&mut self, addr: u16, val: u8) {
+        match MemoryMap::from_addr(addr) {
+            MemoryMap::RomBank0 | MemoryMap::RomBankN | MemoryMap::ExternalRam => {
+                self.cartridge.write(addr, val)
+            }
+            MemoryMap::Vram => self.vram[addr as usize - 0x8000] = val,
+            MemoryMap::Wram => self.wram[(addr as usize - 0xC000) % WRAM_SIZE] = val,
+            MemoryMap::Oam => self.oam[addr as usize - 0xFE00] = val,
+            MemoryMap::Unusable => {}
+            MemoryMap::Io => self.io[addr as usize - 0xFF00] = val,
+            MemoryMap::Hram => self.hram[addr as usize - 0xFF80] = val,
+            MemoryMap::InterruptEnable => self.interrupt_enable = val,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_routing() {
+        assert_eq!(MemoryMap::from_addr(0x0000), MemoryMap::RomBank0);
+        assert_eq!(MemoryMap::from_addr(0x7FFF), MemoryMap::RomBankN);
+        assert_eq!(MemoryMap::from_addr(0x8000), MemoryMap::Vram);
+        assert_eq!(MemoryMap::from_addr(0xC000), MemoryMap::Wram);
+        assert_eq!(MemoryMap::from_addr(0xFE00), MemoryMap::Oam);
+        assert_eq!(MemoryMap::from_addr(0xFF80), MemoryMap::Hram);
+        assert_eq!(MemoryMap::from_addr(0xFFFF), MemoryMap::InterruptEnable);
+    }
+
+    #[test]
+    fn test_vram_wram_round_trip() {
+        let mut bus = Bus::with_rom(vec![0; 0x8000]);
+        bus.write(0x8000, 0xAB);
+        assert_eq!(bus.read(0x8000), 0xAB);
+
+        bus.write(0xC000, 0xCD);
+        assert_eq!(bus.read(0xC000), 0xCD);
+        // 0xE000-0xFDFF echoes work RAM.
+        assert_eq!(bus.read(0xE000), 0xCD);
+    }
+
+    #[test]
+    fn test_interrupt_enable_register() {
+        let mut bus = Bus::with_rom(vec![0; 0x8000]);
+        bus.write(0xFFFF, 0x1F);
+        assert_eq!(bus.read(0xFFFF), 0x1F);
+        assert_eq!(bus.interrupt_enable(), 0x1F);
+    }
+}
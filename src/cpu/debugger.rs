@@ -0,0 +1,191 @@
+//! A thin debugging harness around [`CpuState`], giving a front-end
+//! (TUI, script, remote console, ...) a stable hook for single-stepping,
+//! breakpoints and register inspection without teaching the core anything
+//! about UI concerns.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use super::CpuState;
+use super::registers::{Reg8, RwRegister};
+
+/// Operations a debugger front-end needs around a running [`CpuState`].
+pub trait Debuggable {
+    /// Steps the CPU once, unless `pc` is currently sitting on a breakpoint,
+    /// in which case nothing is executed and `None` is returned.
+    fn step(&mut self) -> Option<u8>;
+
+    /// Sets a breakpoint at `addr` if none is set, or clears it otherwise.
+    fn toggle_breakpoint(&mut self, addr: u16);
+
+    /// Renders every register and flag as human-readable text.
+    fn dump_registers(&self) -> String;
+
+    /// Overwrites a single 8-bit register, as if by `set_$reg` on the
+    /// underlying `RawRegisters`.
+    fn set_register(&mut self, reg: Reg8, val: u8);
+}
+
+/// Wraps a [`CpuState`] with breakpoint tracking and a string command
+/// dispatcher, so a front-end can drive it without depending on the CPU's
+/// internal types.
+pub struct Debugger {
+    state: CpuState,
+    breakpoints: HashSet<u16>,
+}
+
+impl Debugger {
+    pub fn new(state: CpuState) -> Self {
+        Self {
+            state,
+            breakpoints: HashSet::new(),
+        }
+    }
+
+    pub fn state(&self) -> &CpuState {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut CpuState {
+        &mut self.state
+    }
+
+    /// Parses and runs a single debugger command, returning the text a
+    /// front-end should print in response. Recognized commands:
+    ///
+    /// - `s` steps the CPU once.
+    /// - `b <addr>` toggles a breakpoint at `addr` (hex, with or without
+    ///   a leading `0x`).
+    /// - `r` dumps every register and flag.
+    /// - `set <reg> <val>` writes `val` (hex) into an 8-bit register named
+    ///   by [`Reg8`]'s variant, e.g. `set l 0xff`.
+    pub fn dispatch(&mut self, cmd: &str) -> String {
+        let mut parts = cmd.split_whitespace();
+        match parts.next() {
+            Some("s") => match self.step() {
+                Some(cycles) => format!("stepped ({cycles} cycles)"),
+                None => format!("breakpoint hit at {:#06x}", self.state.pc),
+            },
+            Some("b") => match parts.next().and_then(parse_hex16) {
+                Some(addr) => {
+                    self.toggle_breakpoint(addr);
+                    format!("breakpoint toggled at {addr:#06x}")
+                }
+                None => "usage: b <addr>".to_string(),
+            },
+            Some("r") => self.dump_registers(),
+            Some("set") => match (parts.next().and_then(parse_reg8), parts.next().and_then(parse_hex8)) {
+                (Some(reg), Some(val)) => {
+                    self.set_register(reg, val);
+                    format!("{reg:?} = {val:#04x}")
+                }
+                _ => "usage: set <reg> <val>".to_string(),
+            },
+            _ => format!("unknown command: {cmd}"),
+        }
+    }
+}
+
+impl Debuggable for Debugger {
+    fn step(&mut self) -> Option<u8> {
+        if self.breakpoints.contains(&self.state.pc) {
+            return None;
+        }
+        Some(self.state.step())
+    }
+
+    fn toggle_breakpoint(&mut self, addr: u16) {
+        if !self.breakpoints.remove(&addr) {
+            self.breakpoints.insert(addr);
+        }
+    }
+
+    fn dump_registers(&self) -> String {
+        let regs = &self.state.regs;
+        let flags = &self.state.flags;
+        let mut out = String::new();
+        let _ = writeln!(out, "pc={:#06x} sp={:#06x}", self.state.pc, regs.sp());
+        let _ = writeln!(
+            out,
+            "a={:#04x} b={:#04x} c={:#04x} d={:#04x} e={:#04x} h={:#04x} l={:#04x}",
+            regs.acc(),
+            regs.b(),
+            regs.c(),
+            regs.d(),
+            regs.e(),
+            regs.h(),
+            regs.l(),
+        );
+        let _ = write!(
+            out,
+            "z={} n={} h={} c={}",
+            flags.zero() as u8,
+            flags.subtract() as u8,
+            flags.half_carry() as u8,
+            flags.carry() as u8,
+        );
+        out
+    }
+
+    fn set_register(&mut self, reg: Reg8, val: u8) {
+        reg.write(&mut self.state.regs, val);
+    }
+}
+
+fn parse_hex8(s: &str) -> Option<u8> {
+    u8::from_str_radix(s.trim_start_matches("0x"), 16).ok()
+}
+
+fn parse_hex16(s: &str) -> Option<u16> {
+    u16::from_str_radix(s.trim_start_matches("0x"), 16).ok()
+}
+
+fn parse_reg8(s: &str) -> Option<Reg8> {
+    match s.to_ascii_lowercase().as_str() {
+        "a" => Some(Reg8::Acc),
+        "b" => Some(Reg8::B),
+        "c" => Some(Reg8::C),
+        "d" => Some(Reg8::D),
+        "e" => Some(Reg8::E),
+        "h" => Some(Reg8::H),
+        "l" => Some(Reg8::L),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::bus::Bus;
+
+    #[test]
+    fn test_breakpoint_stops_step() {
+        let mut dbg = Debugger::new(CpuState::with_bus(Bus::with_rom(vec![0x00, 0x00])));
+        dbg.toggle_breakpoint(0x0000);
+
+        assert_eq!(dbg.step(), None);
+        assert_eq!(dbg.state().pc, 0x0000);
+
+        dbg.toggle_breakpoint(0x0000);
+        // Once cleared, `step` actually executes the NOP sitting at pc and
+        // advances past it, rather than just reporting "not blocked".
+        assert_eq!(dbg.step(), Some(4));
+        assert_eq!(dbg.state().pc, 0x0001);
+    }
+
+    #[test]
+    fn test_set_register_command() {
+        let mut dbg = Debugger::new(CpuState::new());
+        let reply = dbg.dispatch("set l 0xff");
+        assert_eq!(reply, "L = 0xff");
+        assert_eq!(dbg.state().regs.l(), 0xFF);
+    }
+
+    #[test]
+    fn test_dump_registers_command() {
+        let mut dbg = Debugger::new(CpuState::new());
+        let dump = dbg.dispatch("r");
+        assert!(dump.contains("pc=0x0000"));
+        assert!(dump.contains("a=0x00"));
+    }
+}
@@ -0,0 +1,342 @@
+//! The `0xCB`-prefixed
+//! [rotate/shift/bit instructions](https://gbdev.io/pandocs/CPU_Instruction_Set.html#cb-prefix-instructions).
+//!
+//! Every instruction in this family is exactly 2 bytes long (the `0xCB`
+//! prefix plus the instruction byte), regardless of whether it operates on
+//! a register or `(HL)`.
+
+use crate::cpu::CpuState;
+
+use super::{
+    Executable,
+    operands::{BitSource, Sink, Source},
+};
+
+/// Byte length of every `0xCB`-prefixed instruction.
+const CB_INSTR_LEN: u16 = 2;
+
+/// Number of T-cycles a `0xCB`-prefixed instruction takes: a register
+/// operand is 2 M-cycles (prefix + instruction fetch), while `(HL)`
+/// costs an extra M-cycle to read (and, for writes, another to write back).
+fn cycles_for(src: &BitSource, writes_back: bool) -> u8 {
+    match src {
+        BitSource::Reg(_) => 8,
+        BitSource::Addr if writes_back => 16,
+        BitSource::Addr => 12,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rlc(pub BitSource);
+
+impl Executable for Rlc {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let carry_out = val & 0x80 != 0;
+        let result = val.rotate_left(1);
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rrc(pub BitSource);
+
+impl Executable for Rrc {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let carry_out = val & 0x01 != 0;
+        let result = val.rotate_right(1);
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rl(pub BitSource);
+
+impl Executable for Rl {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let carry_in = state.flags.carry() as u8;
+        let carry_out = val & 0x80 != 0;
+        let result = (val << 1) | carry_in;
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rr(pub BitSource);
+
+impl Executable for Rr {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let carry_in = state.flags.carry() as u8;
+        let carry_out = val & 0x01 != 0;
+        let result = (val >> 1) | (carry_in << 7);
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sla(pub BitSource);
+
+impl Executable for Sla {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let carry_out = val & 0x80 != 0;
+        let result = val << 1;
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sra(pub BitSource);
+
+impl Executable for Sra {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let carry_out = val & 0x01 != 0;
+        // Arithmetic shift: bit 7 is preserved instead of shifted in as 0.
+        let result = (val >> 1) | (val & 0x80);
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Srl(pub BitSource);
+
+impl Executable for Srl {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let carry_out = val & 0x01 != 0;
+        let result = val >> 1;
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Swap(pub BitSource);
+
+impl Executable for Swap {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.0.value(state);
+        let result = val.rotate_left(4);
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.clear_carry();
+
+        state.pc += CB_INSTR_LEN;
+        self.0.set_value(state, result);
+        cycles_for(&self.0, true)
+    }
+}
+
+/// `BIT b, r`: tests bit `b` of `r`, setting the zero flag to its
+/// complement. Does not modify `r` or the carry flag.
+#[derive(Debug, PartialEq)]
+pub struct Bit(pub u8, pub BitSource);
+
+impl Executable for Bit {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.1.value(state);
+        let bit_set = val & (1 << self.0) != 0;
+
+        state.flags.set_zero(!bit_set);
+        state.flags.clear_subtract();
+        state.flags.set_half_carry(true);
+
+        state.pc += CB_INSTR_LEN;
+        cycles_for(&self.1, false)
+    }
+}
+
+/// `RES b, r`: clears bit `b` of `r`. Does not affect any flags.
+#[derive(Debug, PartialEq)]
+pub struct Res(pub u8, pub BitSource);
+
+impl Executable for Res {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.1.value(state);
+        let result = val & !(1 << self.0);
+
+        state.pc += CB_INSTR_LEN;
+        self.1.set_value(state, result);
+        cycles_for(&self.1, true)
+    }
+}
+
+/// `SET b, r`: sets bit `b` of `r`. Does not affect any flags.
+#[derive(Debug, PartialEq)]
+pub struct Set(pub u8, pub BitSource);
+
+impl Executable for Set {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let val = self.1.value(state);
+        let result = val | (1 << self.0);
+
+        state.pc += CB_INSTR_LEN;
+        self.1.set_value(state, result);
+        cycles_for(&self.1, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::bus::Addressable;
+    use crate::cpu::registers::Reg8::*;
+    use BitSource::*;
+
+    #[test]
+    fn test_rotates() {
+        let mut state = CpuState::new();
+        state.regs.set_b(0b1000_0001);
+
+        Rlc(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b0000_0011);
+        assert!(state.flags.carry() == true);
+
+        Rrc(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b1000_0001);
+        assert!(state.flags.carry() == true);
+
+        state.flags.set_carry(false);
+        Rl(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b0000_0010);
+        assert!(state.flags.carry() == true);
+
+        Rr(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b1000_0001);
+        assert!(state.flags.carry() == false);
+    }
+
+    #[test]
+    fn test_shifts_and_swap() {
+        let mut state = CpuState::new();
+        state.regs.set_b(0b1100_0001);
+
+        Sla(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b1000_0010);
+        assert!(state.flags.carry() == true);
+
+        Sra(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b1100_0001);
+        assert!(state.flags.carry() == false);
+
+        Srl(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b0110_0000);
+        assert!(state.flags.carry() == true);
+
+        Swap(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b0000_0110);
+        assert!(state.flags.carry() == false);
+    }
+
+    #[test]
+    fn test_bit_res_set() {
+        let mut state = CpuState::new();
+        state.regs.set_b(0b0000_0100);
+
+        Bit(2, Reg(B)).execute(&mut state);
+        assert!(state.flags.zero() == false);
+        assert!(state.flags.half_carry() == true);
+
+        Bit(0, Reg(B)).execute(&mut state);
+        assert!(state.flags.zero() == true);
+
+        Res(2, Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b0000_0000);
+
+        Set(7, Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.b(), 0b1000_0000);
+    }
+
+    #[test]
+    fn test_bit_preserves_carry_and_rotate_targets_hl() {
+        let mut state = CpuState::new();
+        state.flags.set_carry(true);
+        state.regs.set_b(0b0000_0100);
+
+        // `BIT` only ever touches zero/subtract/half-carry.
+        Bit(2, Reg(B)).execute(&mut state);
+        assert!(state.flags.carry() == true);
+
+        // `(HL)` operands round-trip through the bus like register operands.
+        state.regs.set_hl(0xC000);
+        state.bus.write(0xC000, 0b1000_0001);
+        Rlc(Addr).execute(&mut state);
+        assert_eq!(state.bus.read(0xC000), 0b0000_0011);
+        assert!(state.flags.carry() == true);
+    }
+
+    #[test]
+    fn test_cb_cycles_scale_with_operand_kind_and_write_back() {
+        // Register operands are 2 M-cycles. `(HL)` costs an extra M-cycle to
+        // read, and read-modify-write ops (everything but `BIT`) cost yet
+        // another to write the result back.
+        let mut state = CpuState::new();
+        state.regs.set_hl(0xC000);
+        state.bus.write(0xC000, 0b0000_0001);
+
+        assert_eq!(Rlc(Reg(B)).execute(&mut state), 8);
+        assert_eq!(Bit(0, Addr).execute(&mut state), 12);
+        assert_eq!(Set(0, Addr).execute(&mut state), 16);
+    }
+}
@@ -0,0 +1,123 @@
+//! CPU control instructions: interrupt master-enable toggling and `HALT`.
+
+use crate::cpu::{CpuState, bus::Addressable};
+
+use super::Executable;
+
+/// `NOP`: does nothing for one machine cycle.
+#[derive(Debug, PartialEq)]
+pub struct Nop;
+
+impl Executable for Nop {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        state.pc = state.pc.wrapping_add(1);
+        4
+    }
+}
+
+/// `DI`: disables interrupts immediately.
+#[derive(Debug, PartialEq)]
+pub struct Di;
+
+impl Executable for Di {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        state.ime = false;
+        state.ei_delay = 0;
+        state.pc = state.pc.wrapping_add(1);
+        4
+    }
+}
+
+/// `EI`: enables interrupts, but not until after the instruction that
+/// follows this one has executed.
+#[derive(Debug, PartialEq)]
+pub struct Ei;
+
+impl Executable for Ei {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        // Counts down once per `step`, reaching zero (and flipping `ime`)
+        // right after the next instruction finishes.
+        state.ei_delay = 2;
+        state.pc = state.pc.wrapping_add(1);
+        4
+    }
+}
+
+/// `RETI`: pops the return address off the stack and re-enables interrupts,
+/// as if executing `EI` and `RET` back to back but without `EI`'s delay.
+#[derive(Debug, PartialEq)]
+pub struct Reti;
+
+impl Executable for Reti {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let sp = state.regs.sp();
+        let lo = state.bus.read(sp) as u16;
+        let hi = state.bus.read(sp.wrapping_add(1)) as u16;
+        state.regs.set_sp(sp.wrapping_add(2));
+
+        state.pc = (hi << 8) | lo;
+        state.ime = true;
+        16
+    }
+}
+
+/// `HALT`: suspends the CPU until an interrupt is pending, at which point
+/// `CpuState::step` resumes fetching instructions normally.
+#[derive(Debug, PartialEq)]
+pub struct Halt;
+
+impl Executable for Halt {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        state.halted = true;
+        state.pc = state.pc.wrapping_add(1);
+        4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nop_advances_pc() {
+        let mut state = CpuState::new();
+        let cycles = Nop.execute(&mut state);
+        assert_eq!(cycles, 4);
+        assert_eq!(state.pc, 1);
+    }
+
+    #[test]
+    fn test_di_ei_delay() {
+        let mut state = CpuState::new();
+        state.ime = true;
+
+        Di.execute(&mut state);
+        assert!(!state.ime);
+
+        Ei.execute(&mut state);
+        // IME isn't enabled yet: it flips at the start of the second `step`
+        // from here, once the instruction after `EI` has run.
+        assert!(!state.ime);
+        assert_eq!(state.ei_delay, 2);
+    }
+
+    #[test]
+    fn test_reti_pops_return_address_and_enables_ime() {
+        let mut state = CpuState::new();
+        state.regs.set_sp(0xC000);
+        state.bus.write(0xC000, 0x34);
+        state.bus.write(0xC001, 0x12);
+
+        Reti.execute(&mut state);
+        assert_eq!(state.pc, 0x1234);
+        assert!(state.ime);
+        assert_eq!(state.regs.sp(), 0xC002);
+    }
+
+    #[test]
+    fn test_halt_suspends_execution() {
+        let mut state = CpuState::new();
+        Halt.execute(&mut state);
+        assert!(state.halted);
+    }
+}
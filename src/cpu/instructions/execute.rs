@@ -5,6 +5,25 @@ use super::{
     operands::{ArithSource, Source},
 };
 
+/// Number of bytes an [ArithSource] operand consumes, used to advance `pc`
+/// past the instruction once it's been executed.
+fn operand_len(src: &ArithSource) -> u16 {
+    match src {
+        ArithSource::Reg(_) | ArithSource::Addr => 1,
+        ArithSource::Immediate(_) => 2,
+    }
+}
+
+/// Number of T-cycles an [ArithSource]-driven instruction takes: a register
+/// operand is a single 1 M-cycle fetch, while `(HL)`/immediate operands cost
+/// an extra M-cycle to read memory.
+fn cycles_for(src: &ArithSource) -> u8 {
+    match src {
+        ArithSource::Reg(_) => 4,
+        ArithSource::Addr | ArithSource::Immediate(_) => 8,
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Add(pub ArithSource);
 
@@ -15,7 +34,7 @@ impl Add {
 }
 
 impl Executable for Add {
-    fn execute(&self, state: &mut CpuState) {
+    fn execute(&self, state: &mut CpuState) -> u8 {
         let operand = self.0.value(state);
         let acc = state.regs.acc();
         let (result, did_overflow) = acc.overflowing_add(operand);
@@ -27,19 +46,376 @@ impl Executable for Add {
         // operand nibble.
         state.flags.set_half_carry(result & 0x0F < operand & 0x0F);
 
-        // Increment program counter
-        state.pc += match self.0 {
-            ArithSource::Reg(_) | ArithSource::Addr => 1,
-            ArithSource::Immediate(_) => 2,
-        };
-        // Update Acc
+        state.pc += operand_len(&self.0);
         state.regs.set_acc(result);
+        cycles_for(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Adc(pub ArithSource);
+
+impl Adc {
+    pub fn with_source(src: ArithSource) -> Self {
+        Self(src)
+    }
+}
+
+impl Executable for Adc {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let operand = self.0.value(state);
+        let acc = state.regs.acc();
+        let carry_in = state.flags.carry() as u8;
+        let result = acc.wrapping_add(operand).wrapping_add(carry_in);
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.set_half_carry((acc & 0x0F) + (operand & 0x0F) + carry_in > 0x0F);
+        state.flags.set_carry(
+            acc as u16 + operand as u16 + carry_in as u16 > 0xFF,
+        );
+
+        state.pc += operand_len(&self.0);
+        state.regs.set_acc(result);
+        cycles_for(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sub(pub ArithSource);
+
+impl Sub {
+    pub fn with_source(src: ArithSource) -> Self {
+        Self(src)
+    }
+}
+
+impl Executable for Sub {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let operand = self.0.value(state);
+        let acc = state.regs.acc();
+        let result = acc.wrapping_sub(operand);
+
+        state.flags.set_zero(result == 0);
+        state.flags.set_subtract(true);
+        state.flags.set_half_carry((acc & 0x0F) < (operand & 0x0F));
+        state.flags.set_carry(acc < operand);
+
+        state.pc += operand_len(&self.0);
+        state.regs.set_acc(result);
+        cycles_for(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sbc(pub ArithSource);
+
+impl Sbc {
+    pub fn with_source(src: ArithSource) -> Self {
+        Self(src)
+    }
+}
+
+impl Executable for Sbc {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let operand = self.0.value(state);
+        let acc = state.regs.acc();
+        let carry_in = state.flags.carry() as u8;
+        let result = acc.wrapping_sub(operand).wrapping_sub(carry_in);
+
+        state.flags.set_zero(result == 0);
+        state.flags.set_subtract(true);
+        state
+            .flags
+            .set_half_carry((acc & 0x0F) < (operand & 0x0F) + carry_in);
+        state
+            .flags
+            .set_carry((acc as u16) < operand as u16 + carry_in as u16);
+
+        state.pc += operand_len(&self.0);
+        state.regs.set_acc(result);
+        cycles_for(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct And(pub ArithSource);
+
+impl And {
+    pub fn with_source(src: ArithSource) -> Self {
+        Self(src)
+    }
+}
+
+impl Executable for And {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let operand = self.0.value(state);
+        let result = state.regs.acc() & operand;
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.set_half_carry(true);
+        state.flags.clear_carry();
+
+        state.pc += operand_len(&self.0);
+        state.regs.set_acc(result);
+        cycles_for(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Xor(pub ArithSource);
+
+impl Xor {
+    pub fn with_source(src: ArithSource) -> Self {
+        Self(src)
+    }
+}
+
+impl Executable for Xor {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let operand = self.0.value(state);
+        let result = state.regs.acc() ^ operand;
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.clear_carry();
+
+        state.pc += operand_len(&self.0);
+        state.regs.set_acc(result);
+        cycles_for(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Or(pub ArithSource);
+
+impl Or {
+    pub fn with_source(src: ArithSource) -> Self {
+        Self(src)
+    }
+}
+
+impl Executable for Or {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let operand = self.0.value(state);
+        let result = state.regs.acc() | operand;
+
+        state.flags.set_zero(result == 0);
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.clear_carry();
+
+        state.pc += operand_len(&self.0);
+        state.regs.set_acc(result);
+        cycles_for(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cp(pub ArithSource);
+
+impl Cp {
+    pub fn with_source(src: ArithSource) -> Self {
+        Self(src)
+    }
+}
+
+impl Executable for Cp {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        // Same as `Sub`, but the result is discarded: only the flags matter.
+        let operand = self.0.value(state);
+        let acc = state.regs.acc();
+        let result = acc.wrapping_sub(operand);
+
+        state.flags.set_zero(result == 0);
+        state.flags.set_subtract(true);
+        state.flags.set_half_carry((acc & 0x0F) < (operand & 0x0F));
+        state.flags.set_carry(acc < operand);
+
+        state.pc += operand_len(&self.0);
+        cycles_for(&self.0)
+    }
+}
+
+/// `DAA`: adjusts the accumulator into packed BCD after an 8-bit add/subtract,
+/// using the subtract/half-carry/carry flags left over from that operation.
+#[derive(Debug, PartialEq)]
+pub struct Daa;
+
+impl Executable for Daa {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let mut acc = state.regs.acc();
+        let mut carry = state.flags.carry();
+
+        if state.flags.subtract() {
+            if state.flags.half_carry() {
+                acc = acc.wrapping_sub(0x06);
+            }
+            if carry {
+                acc = acc.wrapping_sub(0x60);
+            }
+        } else {
+            if state.flags.half_carry() || (acc & 0x0F) > 0x09 {
+                acc = acc.wrapping_add(0x06);
+            }
+            if carry || acc > 0x99 {
+                acc = acc.wrapping_add(0x60);
+                carry = true;
+            }
+        }
+
+        state.flags.set_zero(acc == 0);
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry);
+
+        state.pc = state.pc.wrapping_add(1);
+        state.regs.set_acc(acc);
+        4
+    }
+}
+
+/// `RLCA`: rotates the accumulator left, putting the rotated-out bit 7 into
+/// both bit 0 and the carry flag. Unlike [Rlc](super::cb::Rlc), the zero flag
+/// is always cleared rather than set from the result.
+#[derive(Debug, PartialEq)]
+pub struct Rlca;
+
+impl Executable for Rlca {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let acc = state.regs.acc();
+        let carry_out = acc & 0x80 != 0;
+        let result = acc.rotate_left(1);
+
+        state.flags.clear_zero();
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc = state.pc.wrapping_add(1);
+        state.regs.set_acc(result);
+        4
+    }
+}
+
+/// `RRCA`: rotates the accumulator right, putting the rotated-out bit 0 into
+/// both bit 7 and the carry flag.
+#[derive(Debug, PartialEq)]
+pub struct Rrca;
+
+impl Executable for Rrca {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let acc = state.regs.acc();
+        let carry_out = acc & 0x01 != 0;
+        let result = acc.rotate_right(1);
+
+        state.flags.clear_zero();
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc = state.pc.wrapping_add(1);
+        state.regs.set_acc(result);
+        4
+    }
+}
+
+/// `RLA`: rotates the accumulator left through the carry flag.
+#[derive(Debug, PartialEq)]
+pub struct Rla;
+
+impl Executable for Rla {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let acc = state.regs.acc();
+        let carry_in = state.flags.carry() as u8;
+        let carry_out = acc & 0x80 != 0;
+        let result = (acc << 1) | carry_in;
+
+        state.flags.clear_zero();
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc = state.pc.wrapping_add(1);
+        state.regs.set_acc(result);
+        4
+    }
+}
+
+/// `RRA`: rotates the accumulator right through the carry flag.
+#[derive(Debug, PartialEq)]
+pub struct Rra;
+
+impl Executable for Rra {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let acc = state.regs.acc();
+        let carry_in = state.flags.carry() as u8;
+        let carry_out = acc & 0x01 != 0;
+        let result = (acc >> 1) | (carry_in << 7);
+
+        state.flags.clear_zero();
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(carry_out);
+
+        state.pc = state.pc.wrapping_add(1);
+        state.regs.set_acc(result);
+        4
+    }
+}
+
+/// `CPL`: one's-complements the accumulator. Only subtract and half-carry
+/// are affected; zero and carry are left as they were.
+#[derive(Debug, PartialEq)]
+pub struct Cpl;
+
+impl Executable for Cpl {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        state.regs.set_acc(!state.regs.acc());
+        state.flags.set_subtract(true);
+        state.flags.set_half_carry(true);
+
+        state.pc = state.pc.wrapping_add(1);
+        4
+    }
+}
+
+/// `SCF`: sets the carry flag, clearing subtract and half-carry.
+#[derive(Debug, PartialEq)]
+pub struct Scf;
+
+impl Executable for Scf {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(true);
+
+        state.pc = state.pc.wrapping_add(1);
+        4
+    }
+}
+
+/// `CCF`: flips the carry flag, clearing subtract and half-carry.
+#[derive(Debug, PartialEq)]
+pub struct Ccf;
+
+impl Executable for Ccf {
+    fn execute(&self, state: &mut CpuState) -> u8 {
+        let carry = state.flags.carry();
+        state.flags.clear_subtract();
+        state.flags.clear_half_carry();
+        state.flags.set_carry(!carry);
+
+        state.pc = state.pc.wrapping_add(1);
+        4
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cpu::bus::Addressable;
     use crate::cpu::registers::Reg8::*;
     use ArithSource::*;
 
@@ -67,8 +443,202 @@ mod tests {
         assert!(state.flags.carry() == true);
         assert!(state.flags.half_carry() == true);
 
+        state.regs.set_hl(0xC000);
+        state.bus.write(0xC000, 0x00);
         Add(Addr).execute(&mut state);
         assert_eq!(state.regs.acc(), 0x00);
         assert!(state.flags.zero() == true);
     }
+
+    #[test]
+    fn test_adc() {
+        let mut state = CpuState::new();
+        state.regs.set_acc(0xF0);
+        state.regs.set_b(0x0F);
+
+        Add(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0xFF);
+        assert!(state.flags.carry() == false);
+
+        // With the carry flag set, `Adc` folds it into the sum.
+        state.regs.set_acc(0xF0);
+        state.flags.set_carry(true);
+        Adc(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x00);
+        assert!(state.flags.zero() == true);
+        assert!(state.flags.carry() == true);
+        assert!(state.flags.half_carry() == true);
+    }
+
+    #[test]
+    fn test_sub_and_cp() {
+        let mut state = CpuState::new();
+        state.regs.set_acc(0x10);
+        state.regs.set_b(0x01);
+
+        Cp(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x10);
+        assert!(state.flags.subtract() == true);
+        assert!(state.flags.half_carry() == true);
+
+        Sub(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x0F);
+        assert!(state.flags.carry() == false);
+
+        Sub(Immediate(0xFF)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x10);
+        assert!(state.flags.carry() == true);
+    }
+
+    #[test]
+    fn test_sbc() {
+        let mut state = CpuState::new();
+        state.regs.set_acc(0x00);
+        state.flags.set_carry(true);
+
+        Sbc(Immediate(0x00)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0xFF);
+        assert!(state.flags.carry() == true);
+        assert!(state.flags.half_carry() == true);
+    }
+
+    #[test]
+    fn test_adc_folds_incoming_carry_into_half_carry() {
+        // 0x0F + 0x00 alone wouldn't half-carry, but folding in the carry
+        // bit from a previous op pushes the low nibble over 0xF.
+        let mut state = CpuState::new();
+        state.regs.set_acc(0x0F);
+        state.flags.set_carry(true);
+
+        Adc(Immediate(0x00)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x10);
+        assert!(state.flags.half_carry() == true);
+        assert!(state.flags.carry() == false);
+    }
+
+    #[test]
+    fn test_bitwise_ops() {
+        let mut state = CpuState::new();
+        state.regs.set_acc(0b1100);
+        state.regs.set_b(0b1010);
+
+        And(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b1000);
+        assert!(state.flags.half_carry() == true);
+        assert!(state.flags.carry() == false);
+
+        state.regs.set_b(0b1010);
+        Xor(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b0010);
+        assert!(state.flags.half_carry() == false);
+
+        state.regs.set_b(0b0100);
+        Or(Reg(B)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b0110);
+
+        state.regs.set_acc(0x00);
+        state.regs.set_b(0x00);
+        And(Reg(B)).execute(&mut state);
+        assert!(state.flags.zero() == true);
+    }
+
+    #[test]
+    fn test_daa() {
+        let mut state = CpuState::new();
+
+        // 0x45 + 0x38 = 0x7D in binary, but 45 + 38 == 83 in BCD.
+        state.regs.set_acc(0x45);
+        Add(Immediate(0x38)).execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x7D);
+
+        Daa.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x83);
+        assert!(state.flags.carry() == false);
+        assert!(state.flags.half_carry() == false);
+
+        // 0x90 + 0x90 = 0x20 with carry set, corrected to BCD 180 -> 0x80 carry.
+        state.regs.set_acc(0x90);
+        Add(Immediate(0x90)).execute(&mut state);
+        Daa.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x80);
+        assert!(state.flags.carry() == true);
+
+        // 0x50 - 0x19 = 0x37 in binary, but 50 - 19 == 31 in BCD: the borrow
+        // out of the low nibble sets half-carry, so DAA subtracts 0x06.
+        state.regs.set_acc(0x50);
+        Sub(Immediate(0x19)).execute(&mut state);
+        Daa.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x31);
+    }
+
+    #[test]
+    fn test_arith_cycles_scale_with_operand_kind() {
+        // Register operands are a single M-cycle fetch; `(HL)` and immediate
+        // operands cost an extra M-cycle to read, regardless of which ALU op
+        // is doing the reading.
+        let mut state = CpuState::new();
+        state.regs.set_hl(0xC000);
+        state.bus.write(0xC000, 0x01);
+
+        assert_eq!(Add(Reg(B)).execute(&mut state), 4);
+        assert_eq!(Sub(Immediate(0x01)).execute(&mut state), 8);
+        assert_eq!(Cp(Addr).execute(&mut state), 8);
+    }
+
+    #[test]
+    fn test_accumulator_rotates_always_clear_zero() {
+        let mut state = CpuState::new();
+        state.regs.set_acc(0x00);
+
+        // Unlike `RLC`/`RRC`/`RL`/`RR`, the A-register rotates never set zero
+        // from the result, even when it's zero.
+        Rlca.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0x00);
+        assert!(state.flags.zero() == false);
+        assert!(state.flags.carry() == false);
+
+        state.regs.set_acc(0b1000_0001);
+        Rlca.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b0000_0011);
+        assert!(state.flags.carry() == true);
+
+        Rrca.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b1000_0001);
+        assert!(state.flags.carry() == true);
+
+        state.flags.set_carry(false);
+        Rla.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b0000_0010);
+        assert!(state.flags.carry() == true);
+
+        Rra.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b1000_0001);
+        assert!(state.flags.carry() == false);
+    }
+
+    #[test]
+    fn test_cpl_scf_ccf() {
+        let mut state = CpuState::new();
+        state.regs.set_acc(0b1010_0101);
+        state.flags.set_zero(true);
+
+        Cpl.execute(&mut state);
+        assert_eq!(state.regs.acc(), 0b0101_1010);
+        assert!(state.flags.subtract() == true);
+        assert!(state.flags.half_carry() == true);
+        // Zero and carry are left untouched by CPL.
+        assert!(state.flags.zero() == true);
+        assert!(state.flags.carry() == false);
+
+        Scf.execute(&mut state);
+        assert!(state.flags.carry() == true);
+        assert!(state.flags.subtract() == false);
+        assert!(state.flags.half_carry() == false);
+
+        Ccf.execute(&mut state);
+        assert!(state.flags.carry() == false);
+
+        Ccf.execute(&mut state);
+        assert!(state.flags.carry() == true);
+    }
 }
@@ -9,31 +9,104 @@
 //! | 2     | 1            | 0            | X X X X X X     |
 //! | 3     | 1            | 1            | X X X X X X     |
 
+use cb::*;
+use control::*;
 use execute::*;
 
 use super::CpuState;
 
+mod cb;
+mod control;
+pub mod disasm;
 mod execute;
 mod operands;
-mod parsers;
+pub mod parsers;
 
+/// Something the CPU can execute. Returns the number of T-cycles (1/4 of a
+/// machine cycle) the instruction took, so a caller can keep the rest of the
+/// system (PPU, APU, timers) in sync with the CPU clock.
 pub trait Executable {
-    fn execute(&self, state: &mut CpuState);
+    fn execute(&self, state: &mut CpuState) -> u8;
 }
 
 #[rustfmt::skip]
 #[derive(Debug, PartialEq)]
 pub enum Instruction {
     AddInstr(Add),
+    AdcInstr(Adc),
+    SubInstr(Sub),
+    SbcInstr(Sbc),
+    AndInstr(And),
+    XorInstr(Xor),
+    OrInstr(Or),
+    CpInstr(Cp),
+    DaaInstr(Daa),
+    RlcaInstr(Rlca),
+    RrcaInstr(Rrca),
+    RlaInstr(Rla),
+    RraInstr(Rra),
+    CplInstr(Cpl),
+    ScfInstr(Scf),
+    CcfInstr(Ccf),
+    RlcInstr(Rlc),
+    RrcInstr(Rrc),
+    RlInstr(Rl),
+    RrInstr(Rr),
+    SlaInstr(Sla),
+    SraInstr(Sra),
+    SrlInstr(Srl),
+    SwapInstr(Swap),
+    BitInstr(Bit),
+    ResInstr(Res),
+    SetInstr(Set),
+    NopInstr(Nop),
+    DiInstr(Di),
+    EiInstr(Ei),
+    RetiInstr(Reti),
+    HaltInstr(Halt),
 }
 
 impl Executable for Instruction {
-    fn execute(&self, state: &mut CpuState) {
+    fn execute(&self, state: &mut CpuState) -> u8 {
         use Instruction::*;
 
-        state.flags.clear();
+        // Each `Executable` impl sets every flag bit it's responsible for
+        // explicitly (mirroring real hardware), rather than relying on a
+        // blanket clear here: ops like `Adc`/`Sbc` need to read the carry
+        // flag left over from the previous instruction.
         match self {
             AddInstr(i) => i.execute(state),
+            AdcInstr(i) => i.execute(state),
+            SubInstr(i) => i.execute(state),
+            SbcInstr(i) => i.execute(state),
+            AndInstr(i) => i.execute(state),
+            XorInstr(i) => i.execute(state),
+            OrInstr(i) => i.execute(state),
+            CpInstr(i) => i.execute(state),
+            DaaInstr(i) => i.execute(state),
+            RlcaInstr(i) => i.execute(state),
+            RrcaInstr(i) => i.execute(state),
+            RlaInstr(i) => i.execute(state),
+            RraInstr(i) => i.execute(state),
+            CplInstr(i) => i.execute(state),
+            ScfInstr(i) => i.execute(state),
+            CcfInstr(i) => i.execute(state),
+            RlcInstr(i) => i.execute(state),
+            RrcInstr(i) => i.execute(state),
+            RlInstr(i) => i.execute(state),
+            RrInstr(i) => i.execute(state),
+            SlaInstr(i) => i.execute(state),
+            SraInstr(i) => i.execute(state),
+            SrlInstr(i) => i.execute(state),
+            SwapInstr(i) => i.execute(state),
+            BitInstr(i) => i.execute(state),
+            ResInstr(i) => i.execute(state),
+            SetInstr(i) => i.execute(state),
+            NopInstr(i) => i.execute(state),
+            DiInstr(i) => i.execute(state),
+            EiInstr(i) => i.execute(state),
+            RetiInstr(i) => i.execute(state),
+            HaltInstr(i) => i.execute(state),
         }
     }
 }
@@ -63,4 +136,61 @@ mod tests {
         let i = InstructionDecoder::from(0xC6).decode(&[0xC6, 0xAB]);
         assert_eq!(i, Ok(AddInstr(Add(Immediate(0xAB)))));
     }
+
+    #[test]
+    fn test_alu_block_parse() {
+        use crate::cpu::registers::Reg8::*;
+        use operands::ArithSource::*;
+
+        // sub a, r8 / cp a, r8
+        let i = InstructionDecoder::from(0x90).decode(&[0x90]);
+        assert_eq!(i, Ok(SubInstr(Sub(Reg(B)))));
+        let i = InstructionDecoder::from(0xBF).decode(&[0xBF]);
+        assert_eq!(i, Ok(CpInstr(Cp(Reg(Acc)))));
+
+        // and a, imm8 / xor a, imm8
+        let i = InstructionDecoder::from(0xE6).decode(&[0xE6, 0x0F]);
+        assert_eq!(i, Ok(AndInstr(And(Immediate(0x0F)))));
+        let i = InstructionDecoder::from(0xEE).decode(&[0xEE, 0xFF]);
+        assert_eq!(i, Ok(XorInstr(Xor(Immediate(0xFF)))));
+    }
+
+    #[test]
+    fn test_cb_prefixed_parse() {
+        use crate::cpu::registers::Reg8::*;
+        use operands::BitSource::*;
+
+        // rlc b
+        let i = InstructionDecoder::from(0xCB).decode(&[0xCB, 0x00]);
+        assert_eq!(i, Ok(RlcInstr(Rlc(Reg(B)))));
+
+        // bit 3, (hl)
+        let i = InstructionDecoder::from(0xCB).decode(&[0xCB, 0x5E]);
+        assert_eq!(i, Ok(BitInstr(Bit(3, Addr))));
+
+        // set 7, a
+        let i = InstructionDecoder::from(0xCB).decode(&[0xCB, 0xFF]);
+        assert_eq!(i, Ok(SetInstr(Set(7, Reg(Acc)))));
+    }
+
+    #[test]
+    fn test_unimplemented_opcodes_report_invalid_instead_of_panicking() {
+        use super::parsers::DecodeError;
+
+        // `LD BC, d16` (block 0), `LD B, C` (block 1) and `JP a16` (block 3)
+        // aren't modeled by `Instruction` yet; decoding them should report
+        // `DecodeError::Invalid` rather than hitting a `todo!()`.
+        assert_eq!(
+            InstructionDecoder::from(0x01).decode(&[0x01, 0x00, 0x00]),
+            Err(DecodeError::Invalid)
+        );
+        assert_eq!(
+            InstructionDecoder::from(0x41).decode(&[0x41]),
+            Err(DecodeError::Invalid)
+        );
+        assert_eq!(
+            InstructionDecoder::from(0xC3).decode(&[0xC3, 0x00, 0x00]),
+            Err(DecodeError::Invalid)
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use crate::cpu::{
     CpuState,
+    bus::Addressable,
     registers::{Reg8, RwRegister},
 };
 
@@ -49,7 +50,64 @@ impl Source<u8> for ArithSource {
         match *self {
             Self::Immediate(imm) => imm,
             Self::Reg(reg) => reg.read(&state.regs),
-            Self::Addr => todo!(),
+            Self::Addr => state.bus.read(state.regs.hl()),
+        }
+    }
+}
+
+/// Something a value can be written back into, such as a register or an
+/// address in memory.
+pub trait Sink<T>: Sized {
+    fn set_value(&self, state: &mut CpuState, val: T);
+}
+
+/// Target operand for the 0xCB-prefixed
+/// [rotate/shift/bit](https://gbdev.io/pandocs/CPU_Instruction_Set.html#cb-prefix-instructions)
+/// instructions: an 8-bit register or the byte pointed to by `HL`.
+#[derive(Debug, PartialEq)]
+pub enum BitSource {
+    /// An 8-bit register.
+    Reg(Reg8),
+    /// An 16-bit address into the GameBoy's memory, read from the HL register.
+    Addr,
+}
+
+impl BitSource {
+    pub fn from_opcode(opcode: u8) -> Self {
+        // The three last bits of the opcode
+        let reg_idx = opcode & 0b00000111;
+
+        match reg_idx {
+            0 => Self::Reg(Reg8::B),
+            1 => Self::Reg(Reg8::C),
+            2 => Self::Reg(Reg8::D),
+            3 => Self::Reg(Reg8::E),
+            4 => Self::Reg(Reg8::H),
+            5 => Self::Reg(Reg8::L),
+            6 => Self::Addr,
+            7 => Self::Reg(Reg8::Acc),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Source<u8> for BitSource {
+    fn value(&self, state: &CpuState) -> u8 {
+        match *self {
+            Self::Reg(reg) => reg.read(&state.regs),
+            Self::Addr => state.bus.read(state.regs.hl()),
+        }
+    }
+}
+
+impl Sink<u8> for BitSource {
+    fn set_value(&self, state: &mut CpuState, val: u8) {
+        match *self {
+            Self::Reg(reg) => reg.write(&mut state.regs, val),
+            Self::Addr => {
+                let addr = state.regs.hl();
+                state.bus.write(addr, val);
+            }
         }
     }
 }
@@ -1,13 +1,19 @@
 use super::{
     Instruction::{self, *},
+    cb::*,
+    control::*,
     execute::*,
-    operands::ArithSource,
+    operands::{ArithSource, BitSource},
 };
 
 const ARITH_INSTR_MASK: u8 = 0b00111000;
 const ARITH_INSTR_POS: u8 = 3;
 const BLOCK_3_INSTR_MASK: u8 = 0b00000111;
 const BLOCK_3_INSTR_POS: u8 = 0;
+const CB_GROUP_MASK: u8 = 0b11000000;
+const CB_GROUP_POS: u8 = 6;
+const CB_BIT_INDEX_MASK: u8 = 0b00111000;
+const CB_BIT_INDEX_POS: u8 = 3;
 
 #[derive(Debug, PartialEq)]
 pub enum DecodeError {
@@ -24,8 +30,27 @@ pub struct Block0;
 impl Decode for Block0 {
     type Error = DecodeError;
 
-    fn decode(self, _bytes: &[u8]) -> Result<Instruction, Self::Error> {
-        todo!()
+    fn decode(self, bytes: &[u8]) -> Result<Instruction, Self::Error> {
+        let [opcode, ..] = bytes else {
+            return Err(DecodeError::Invalid);
+        };
+
+        let parsed = match *opcode {
+            0x00 => NopInstr(Nop),
+            0x07 => RlcaInstr(Rlca),
+            0x0F => RrcaInstr(Rrca),
+            0x17 => RlaInstr(Rla),
+            0x1F => RraInstr(Rra),
+            0x27 => DaaInstr(Daa),
+            0x2F => CplInstr(Cpl),
+            0x37 => ScfInstr(Scf),
+            0x3F => CcfInstr(Ccf),
+            // Not yet modeled by `Instruction` (e.g. 8/16-bit loads, INC/DEC,
+            // JR): report it the same way a too-short byte slice is reported,
+            // rather than panicking, so callers can decide how to cope.
+            _ => return Err(DecodeError::Invalid),
+        };
+        Ok(parsed)
     }
 }
 
@@ -34,8 +59,18 @@ pub struct Block1;
 impl Decode for Block1 {
     type Error = DecodeError;
 
-    fn decode(self, _bytes: &[u8]) -> Result<Instruction, Self::Error> {
-        todo!()
+    fn decode(self, bytes: &[u8]) -> Result<Instruction, Self::Error> {
+        let [opcode, ..] = bytes else {
+            return Err(DecodeError::Invalid);
+        };
+
+        let parsed = match *opcode {
+            // `HALT` sits in the slot that would otherwise be `LD (HL), (HL)`.
+            0x76 => HaltInstr(Halt),
+            // The rest of block 1 is `LD r, r'`, not yet modeled.
+            _ => return Err(DecodeError::Invalid),
+        };
+        Ok(parsed)
     }
 }
 
@@ -52,10 +87,17 @@ impl Decode for Block2 {
         // The opcode without the bits encoding the block and the source register.
         let instr = (opcode & ARITH_INSTR_MASK) >> ARITH_INSTR_POS;
 
+        let src = ArithSource::from_opcode(*opcode);
         let parsed = match instr {
-            0 => AddInstr(Add::with_source(ArithSource::from_opcode(*opcode))),
-            _ if instr > 7 => unreachable!(),
-            _ => todo!(),
+            0 => AddInstr(Add::with_source(src)),
+            1 => AdcInstr(Adc::with_source(src)),
+            2 => SubInstr(Sub::with_source(src)),
+            3 => SbcInstr(Sbc::with_source(src)),
+            4 => AndInstr(And::with_source(src)),
+            5 => XorInstr(Xor::with_source(src)),
+            6 => OrInstr(Or::with_source(src)),
+            7 => CpInstr(Cp::with_source(src)),
+            _ => unreachable!(),
         };
         Ok(parsed)
     }
@@ -78,14 +120,28 @@ impl Decode for Block3 {
             // The opcode without the bits encoding the block and the arithmetic
             // instruction.
             let instr = (opcode & ARITH_INSTR_MASK) >> ARITH_INSTR_POS;
+            let src = ArithSource::from_literal(*immediate);
 
             match instr {
-                0 => AddInstr(Add::with_source(ArithSource::from_literal(*immediate))),
-                _ if instr > 7 => unreachable!(),
-                _ => todo!(),
+                0 => AddInstr(Add::with_source(src)),
+                1 => AdcInstr(Adc::with_source(src)),
+                2 => SubInstr(Sub::with_source(src)),
+                3 => SbcInstr(Sbc::with_source(src)),
+                4 => AndInstr(And::with_source(src)),
+                5 => XorInstr(Xor::with_source(src)),
+                6 => OrInstr(Or::with_source(src)),
+                7 => CpInstr(Cp::with_source(src)),
+                _ => unreachable!(),
             }
         } else {
-            todo!()
+            match *opcode {
+                0xF3 => DiInstr(Di),
+                0xFB => EiInstr(Ei),
+                0xD9 => RetiInstr(Reti),
+                // The rest of block 3 is jumps/calls/returns/stack ops, not
+                // yet modeled.
+                _ => return Err(DecodeError::Invalid),
+            }
         };
         Ok(parsed)
     }
@@ -96,8 +152,35 @@ pub struct Prefixed;
 impl Decode for Prefixed {
     type Error = DecodeError;
 
-    fn decode(self, _bytes: &[u8]) -> Result<Instruction, Self::Error> {
-        todo!()
+    fn decode(self, bytes: &[u8]) -> Result<Instruction, Self::Error> {
+        let [_prefix, opcode, ..] = bytes else {
+            return Err(DecodeError::Invalid);
+        };
+
+        let src = BitSource::from_opcode(*opcode);
+        let group = (opcode & CB_GROUP_MASK) >> CB_GROUP_POS;
+        let bit_idx = (opcode & CB_BIT_INDEX_MASK) >> CB_BIT_INDEX_POS;
+
+        let parsed = match group {
+            // Rotates/shifts, selected by the bits that would otherwise hold
+            // the bit index.
+            0 => match bit_idx {
+                0 => RlcInstr(Rlc(src)),
+                1 => RrcInstr(Rrc(src)),
+                2 => RlInstr(Rl(src)),
+                3 => RrInstr(Rr(src)),
+                4 => SlaInstr(Sla(src)),
+                5 => SraInstr(Sra(src)),
+                6 => SwapInstr(Swap(src)),
+                7 => SrlInstr(Srl(src)),
+                _ => unreachable!(),
+            },
+            1 => BitInstr(Bit(bit_idx, src)),
+            2 => ResInstr(Res(bit_idx, src)),
+            3 => SetInstr(Set(bit_idx, src)),
+            _ => unreachable!(),
+        };
+        Ok(parsed)
     }
 }
 
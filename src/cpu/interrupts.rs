@@ -0,0 +1,80 @@
+//! The Game Boy's [interrupt controller](https://gbdev.io/pandocs/Interrupts.html):
+//! the `IF` register at `0xFF0F` (the `IE` register at `0xFFFF` lives on the
+//! [`Bus`](super::bus::Bus) itself, since it's memory-mapped) and the fixed
+//! jump vector each interrupt source dispatches to.
+
+/// Address of the `IF` (interrupt flag) register.
+pub const IF_ADDR: u16 = 0xFF0F;
+
+/// The five interrupt sources, in the priority order hardware services them
+/// in when more than one is pending at once.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interrupt {
+    VBlank,
+    LcdStat,
+    Timer,
+    Serial,
+    Joypad,
+}
+
+impl Interrupt {
+    pub const ALL: [Interrupt; 5] = [
+        Interrupt::VBlank,
+        Interrupt::LcdStat,
+        Interrupt::Timer,
+        Interrupt::Serial,
+        Interrupt::Joypad,
+    ];
+
+    /// The bit this interrupt occupies in both the `IE` and `IF` registers.
+    pub fn bit(self) -> u8 {
+        match self {
+            Self::VBlank => 0,
+            Self::LcdStat => 1,
+            Self::Timer => 2,
+            Self::Serial => 3,
+            Self::Joypad => 4,
+        }
+    }
+
+    /// The fixed address execution jumps to once this interrupt is serviced.
+    pub fn vector(self) -> u16 {
+        0x0040 + self.bit() as u16 * 8
+    }
+
+    /// The highest-priority interrupt that's both enabled (`IE`) and
+    /// requested (`IF`), if any.
+    pub fn pending(ie: u8, if_reg: u8) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|i| (ie & if_reg) & (1 << i.bit()) != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_and_vector() {
+        assert_eq!(Interrupt::VBlank.bit(), 0);
+        assert_eq!(Interrupt::VBlank.vector(), 0x40);
+        assert_eq!(Interrupt::Joypad.bit(), 4);
+        assert_eq!(Interrupt::Joypad.vector(), 0x60);
+    }
+
+    #[test]
+    fn test_pending_respects_priority_and_enable_mask() {
+        // Timer and Joypad requested, but only Joypad is enabled.
+        let ie = 1 << Interrupt::Joypad.bit();
+        let if_reg = (1 << Interrupt::Timer.bit()) | (1 << Interrupt::Joypad.bit());
+        assert_eq!(Interrupt::pending(ie, if_reg), Some(Interrupt::Joypad));
+
+        // VBlank and Timer both enabled and requested: VBlank wins.
+        let ie = (1 << Interrupt::VBlank.bit()) | (1 << Interrupt::Timer.bit());
+        let if_reg = ie;
+        assert_eq!(Interrupt::pending(ie, if_reg), Some(Interrupt::VBlank));
+
+        assert_eq!(Interrupt::pending(0xFF, 0x00), None);
+    }
+}